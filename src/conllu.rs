@@ -0,0 +1,129 @@
+//! Minimal CoNLL-U reading, mirroring the subset of the format `dcs::write_conllu` writes.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::ops::{Deref, DerefMut};
+
+/// A token's morphological features, the CoNLL-U FEATS column parsed into key/value pairs.
+#[derive(Debug, Clone, Default)]
+pub struct TokenFeatures(HashMap<String, String>);
+
+impl TokenFeatures {
+    pub fn new() -> Self {
+        TokenFeatures::default()
+    }
+}
+
+impl Deref for TokenFeatures {
+    type Target = HashMap<String, String>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TokenFeatures {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// One CoNLL-U token row: ID, FORM, LEMMA, UPOS, and FEATS. The other mandatory columns
+/// (XPOS, HEAD, DEPREL, DEPS, MISC) aren't used anywhere in this crate, so they're not
+/// modeled here; `dcs::write_conllu_sentence` fills them with the CoNLL-U placeholder `_`.
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    pub id: String,
+    pub form: String,
+    pub lemma: String,
+    pub upos: String,
+    pub features: TokenFeatures,
+}
+
+/// Parses the CoNLL-U FEATS column (`Key1=Val1|Key2=Val2`, or `_` if empty).
+fn parse_features(field: &str) -> TokenFeatures {
+    let mut features = TokenFeatures::new();
+    if field == "_" {
+        return features;
+    }
+    for pair in field.split('|') {
+        if let Some((key, value)) = pair.split_once('=') {
+            features.insert(key.to_string(), value.to_string());
+        }
+    }
+    features
+}
+
+/// Parses CoNLL-U text into one `Vec<Token>` per sentence.
+///
+/// Comment lines (starting with `#`) are skipped and sentences are separated by blank
+/// lines, per the CoNLL-U spec. Only the ID/FORM/LEMMA/UPOS/FEATS columns are kept, since
+/// those are all `dcs::standardize` and `FrequencyModel::from_corpus` read.
+fn parse_conllu(contents: &str) -> Vec<Vec<Token>> {
+    let mut sentences = Vec::new();
+    let mut current = Vec::new();
+    for line in contents.lines() {
+        if line.is_empty() {
+            if !current.is_empty() {
+                sentences.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.split('\t').collect();
+        if cols.len() < 6 {
+            continue;
+        }
+        current.push(Token {
+            id: cols[0].to_string(),
+            form: cols[1].to_string(),
+            lemma: cols[2].to_string(),
+            upos: cols[3].to_string(),
+            features: parse_features(cols[5]),
+        });
+    }
+    if !current.is_empty() {
+        sentences.push(current);
+    }
+    sentences
+}
+
+/// Reads a CoNLL-U file into one `Vec<Token>` per sentence.
+pub fn read_conllu_file(path: &str) -> Result<Vec<Vec<Token>>, Box<dyn Error>> {
+    Ok(parse_conllu(&fs::read_to_string(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sentences_separated_by_blank_lines() {
+        let contents = [
+            "1\trAma\trAma\tNOUN\t_\tCase=Nom|Gender=Masc\t_\t_\t_\t_",
+            "2\tgacCati\tgam\tVERB\t_\tTense=Pres\t_\t_\t_\t_",
+            "",
+            "1\tsa\ttad\tPRON\t_\t_\t_\t_\t_\t_",
+            "",
+        ]
+        .join("\n");
+
+        let sentences = parse_conllu(&contents);
+
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[0].len(), 2);
+        assert_eq!(sentences[0][0].lemma, "rAma");
+        assert_eq!(sentences[0][0].features.get("Case").unwrap(), "Nom");
+        assert_eq!(sentences[1].len(), 1);
+        assert_eq!(sentences[1][0].upos, "PRON");
+    }
+
+    #[test]
+    fn skips_comment_lines() {
+        let contents = "# sent_id = 1\n1\trAma\trAma\tNOUN\t_\t_\t_\t_\t_\t_\n";
+        let sentences = parse_conllu(contents);
+        assert_eq!(sentences.len(), 1);
+        assert_eq!(sentences[0].len(), 1);
+    }
+}
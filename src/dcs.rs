@@ -6,46 +6,73 @@ use crate::translit::to_slp1;
 use std::error::Error;
 use std::fmt;
 
-type Result<T> = std::result::Result<T, Box<dyn Error>>;
+/// One DCS feature that `standardize` couldn't carry over exactly, because Vidyut's
+/// semantics have no slot for it (an unrecognized feature value) or collapse several DCS
+/// distinctions onto the same value (an unsupported tense/mood pair, a missing pada).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnconvertedFeature {
+    pub key: String,
+    pub value: String,
+}
 
-#[derive(Debug, Clone)]
-struct ConversionError(String);
-impl ConversionError {
-    fn new(s: &str) -> Box<Self> {
-        Box::new(ConversionError(s.to_string()))
+impl UnconvertedFeature {
+    fn new(key: &str, value: &str) -> Self {
+        UnconvertedFeature {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
     }
 }
-impl fmt::Display for ConversionError {
+
+/// The only way `standardize` can fail outright: an unrecognized `upos`, since there's no
+/// sensible `Semantics` fallback without knowing which kind of word this even is. An
+/// unrecognized value of a feature that `standardize` reads is reported as an
+/// `UnconvertedFeature` warning instead, so a caller processing a whole corpus can collect
+/// coverage statistics rather than aborting. Features it does not read (such as the value
+/// of `VerbForm`, or `Mood` on a participle) are dropped without a warning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownUpos(String);
+
+impl fmt::Display for UnknownUpos {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Could not parse value `{}`", self.0)
+        write!(f, "Unknown upos `{}`", self.0)
     }
 }
-impl Error for ConversionError {
-    fn description(&self) -> &str {
-        &self.0
-    }
+impl Error for UnknownUpos {}
+
+/// `standardize`'s output: the standardized word plus the features it read whose values
+/// were dropped or fell back to a default along the way. Features it never reads are not
+/// listed.
+#[derive(Debug, Clone)]
+pub struct StandardizeResult {
+    pub word: ParsedWord,
+    pub warnings: Vec<UnconvertedFeature>,
 }
 
 /// Convert DCS semantics to Vidyut semantics.
-pub fn standardize(t: &Token) -> Result<ParsedWord> {
+pub fn standardize(t: &Token) -> Result<StandardizeResult, UnknownUpos> {
+    let mut warnings = Vec::new();
     let semantics = match t.upos.as_str() {
-        "NOUN" | "PRON" | "ADJ" | "PART" | "NUM" => parse_subanta(t)?,
+        "NOUN" | "PRON" | "ADJ" | "PART" | "NUM" => parse_subanta(t, &mut warnings),
         "CCONJ" | "SCONJ" | "ADV" => Semantics::Avyaya,
         "VERB" => {
             if t.features.contains_key("VerbForm") {
-                parse_participle(t)?
+                parse_participle(t, &mut warnings)
             } else {
-                parse_verb(t)?
+                parse_verb(t, &mut warnings)
             }
         }
         "MANTRA" => Semantics::None,
-        _ => panic!("Unknown upos `{}`", t.upos),
+        _ => return Err(UnknownUpos(t.upos.clone())),
     };
 
-    Ok(ParsedWord {
+    Ok(StandardizeResult {
         // The original form is not consistently present in the DCS data, so just use the lemma.
-        text: standardize_lemma(&t.lemma),
-        semantics,
+        word: ParsedWord {
+            text: standardize_lemma(&t.lemma),
+            semantics,
+        },
+        warnings,
     })
 }
 
@@ -70,57 +97,57 @@ fn standardize_lemma(raw_lemma: &str) -> String {
 }
 
 /// Reshapes a DCS nominal into a Vidyut subanta.
-fn parse_subanta(t: &Token) -> Result<Semantics> {
+fn parse_subanta(t: &Token, warnings: &mut Vec<UnconvertedFeature>) -> Semantics {
     let stem = parse_stem(t);
-    let linga = parse_linga(&t.features)?;
-    let vibhakti = parse_vibhakti(&t.features)?;
-    let vacana = parse_vacana(&t.features)?;
+    let linga = parse_linga(&t.features, warnings);
+    let vibhakti = parse_vibhakti(&t.features, warnings);
+    let vacana = parse_vacana(&t.features, warnings);
     let is_purvapada = parse_is_purvapada(&t.features);
 
-    Ok(Semantics::Subanta(Subanta {
+    Semantics::Subanta(Subanta {
         stem,
         linga,
         vacana,
         vibhakti,
         is_purvapada,
-    }))
+    })
 }
 
 /// Reshapes a DCS verb into a Vidyut tinanta.
-fn parse_verb(t: &Token) -> Result<Semantics> {
+fn parse_verb(t: &Token, warnings: &mut Vec<UnconvertedFeature>) -> Semantics {
     let root = standardize_lemma(&t.lemma);
-    let purusha = parse_purusha(&t.features)?;
-    let vacana = parse_vacana(&t.features)?;
-    let lakara = parse_lakara(&t.features)?;
-    let pada = parse_verb_pada(&t.features);
-    Ok(Semantics::Tinanta(Tinanta {
+    let purusha = parse_purusha(&t.features, warnings);
+    let vacana = parse_vacana(&t.features, warnings);
+    let lakara = parse_lakara(&t.features, warnings);
+    let pada = parse_verb_pada(&t.features, warnings);
+    Semantics::Tinanta(Tinanta {
         root,
         purusha,
         vacana,
         lakara,
         pada,
-    }))
+    })
 }
 
 /// Reshapes a DCS participle into a Vidyut krdanta.
-fn parse_participle(t: &Token) -> Result<Semantics> {
+fn parse_participle(t: &Token, warnings: &mut Vec<UnconvertedFeature>) -> Semantics {
     let stem = Stem::Krdanta {
         root: standardize_lemma(&t.lemma),
-        tense: parse_tense(&t.features)?,
+        tense: parse_tense(&t.features, warnings),
         prayoga: StemPrayoga::None,
     };
-    let linga = parse_linga(&t.features)?;
-    let vibhakti = parse_vibhakti(&t.features)?;
-    let vacana = parse_vacana(&t.features)?;
+    let linga = parse_linga(&t.features, warnings);
+    let vibhakti = parse_vibhakti(&t.features, warnings);
+    let vacana = parse_vacana(&t.features, warnings);
     let is_purvapada = parse_is_purvapada(&t.features);
 
-    Ok(Semantics::Subanta(Subanta {
+    Semantics::Subanta(Subanta {
         stem,
         linga,
         vacana,
         vibhakti,
         is_purvapada,
-    }))
+    })
 }
 
 /// Reshapes a DCS stem into a Vidyut stem.
@@ -132,36 +159,40 @@ fn parse_stem(t: &Token) -> Stem {
 }
 
 /// Reshapes a DCS tense into a Vidyut tense.
-fn parse_tense(f: &TokenFeatures) -> Result<StemTense> {
-    let val = match f.get("Tense") {
+fn parse_tense(f: &TokenFeatures, warnings: &mut Vec<UnconvertedFeature>) -> StemTense {
+    match f.get("Tense") {
         Some(s) => match s.as_str() {
             "Pres" => StemTense::Present,
             "Past" => StemTense::Past,
             "Fut" => StemTense::Future,
-            &_ => return Err(ConversionError::new(s)),
+            _ => {
+                warnings.push(UnconvertedFeature::new("Tense", s));
+                StemTense::None
+            }
         },
         None => StemTense::None,
-    };
-    Ok(val)
+    }
 }
 
 /// Reshapes a DCS gender into a Vidyut linga.
-fn parse_linga(f: &TokenFeatures) -> Result<Linga> {
-    let val = match f.get("Gender") {
+fn parse_linga(f: &TokenFeatures, warnings: &mut Vec<UnconvertedFeature>) -> Linga {
+    match f.get("Gender") {
         Some(s) => match s.as_str() {
             "Masc" => Linga::Pum,
             "Fem" => Linga::Stri,
             "Neut" => Linga::Napumsaka,
-            &_ => return Err(ConversionError::new(s)),
+            _ => {
+                warnings.push(UnconvertedFeature::new("Gender", s));
+                Linga::None
+            }
         },
         None => Linga::None,
-    };
-    Ok(val)
+    }
 }
 
 /// Reshapes a DCS case into a Vidyut vibhakti.
-fn parse_vibhakti(f: &TokenFeatures) -> Result<Vibhakti> {
-    let val = match f.get("Case") {
+fn parse_vibhakti(f: &TokenFeatures, warnings: &mut Vec<UnconvertedFeature>) -> Vibhakti {
+    match f.get("Case") {
         Some(s) => match s.as_str() {
             "Nom" => Vibhakti::V1,
             "Acc" => Vibhakti::V2,
@@ -172,11 +203,13 @@ fn parse_vibhakti(f: &TokenFeatures) -> Result<Vibhakti> {
             "Loc" => Vibhakti::V7,
             "Voc" => Vibhakti::Sambodhana,
             "Cpd" => Vibhakti::None,
-            &_ => return Err(ConversionError::new(s)),
+            _ => {
+                warnings.push(UnconvertedFeature::new("Case", s));
+                Vibhakti::None
+            }
         },
         None => Vibhakti::None,
-    };
-    Ok(val)
+    }
 }
 
 /// Reshapes a DCS compound flag.
@@ -191,65 +224,369 @@ fn parse_is_purvapada(f: &TokenFeatures) -> bool {
 }
 
 /// Reshapes a DCS person into a Vidyut purusha.
-fn parse_purusha(f: &TokenFeatures) -> Result<Purusha> {
-    let val = match f.get("Person") {
+fn parse_purusha(f: &TokenFeatures, warnings: &mut Vec<UnconvertedFeature>) -> Purusha {
+    match f.get("Person") {
         Some(s) => match s.as_str() {
             "3" => Purusha::Prathama,
             "2" => Purusha::Madhyama,
             "1" => Purusha::Uttama,
-            &_ => return Err(ConversionError::new(s)),
+            _ => {
+                warnings.push(UnconvertedFeature::new("Person", s));
+                Purusha::None
+            }
         },
         None => Purusha::None,
-    };
-    Ok(val)
+    }
 }
 
 /// Reshapes a DCS number into a Vidyut vacana.
-fn parse_vacana(f: &TokenFeatures) -> Result<Vacana> {
-    let val = match f.get("Number") {
+fn parse_vacana(f: &TokenFeatures, warnings: &mut Vec<UnconvertedFeature>) -> Vacana {
+    match f.get("Number") {
         Some(s) => match s.as_str() {
             "Sing" => Vacana::Eka,
             "Dual" => Vacana::Dvi,
             "Plur" => Vacana::Bahu,
-            &_ => return Err(ConversionError::new("Could not parse number")),
+            _ => {
+                warnings.push(UnconvertedFeature::new("Number", s));
+                Vacana::None
+            }
         },
         None => Vacana::None,
-    };
-    Ok(val)
+    }
 }
 
 /// Reshapes a DCS tense/mood into a Vidyut lakara.
-fn parse_lakara(f: &TokenFeatures) -> Result<Lakara> {
-    let tense = match f.get("Tense") {
-        Some(s) => s,
-        None => return Err(ConversionError::new("`Tense` not found")),
-    };
-    let mood = match f.get("Mood") {
-        Some(s) => s,
-        None => return Err(ConversionError::new("`Mood` not found")),
+///
+/// Several DCS tense/mood pairs (e.g. `Perf`/`Sub`, `Aor`/`Imp`) have no corresponding
+/// lakara and collapse to `Lakara::None`; those collapses are reported via `warnings`
+/// instead of aborting the whole conversion. When only one of `Tense` and `Mood` is
+/// present, that one is reported; a token with neither gets `Lakara::None` and no warning.
+fn parse_lakara(f: &TokenFeatures, warnings: &mut Vec<UnconvertedFeature>) -> Lakara {
+    let (tense, mood) = match (f.get("Tense"), f.get("Mood")) {
+        (Some(tense), Some(mood)) => (tense, mood),
+        (tense, mood) => {
+            if let Some(tense) = tense {
+                warnings.push(UnconvertedFeature::new("Tense", tense));
+            }
+            if let Some(mood) = mood {
+                warnings.push(UnconvertedFeature::new("Mood", mood));
+            }
+            return Lakara::None;
+        }
     };
 
-    let val = match (tense.as_str(), mood.as_str()) {
+    match (tense.as_str(), mood.as_str()) {
         ("Aor", "Ind") => Lakara::Lun,
-        ("Aor", "Imp") => Lakara::None,
         ("Aor", "Jus") => Lakara::LunNoAgama,
         ("Aor", "Prec") => Lakara::LinAshih,
         ("Fut", "Cond") => Lakara::Lrn,
         ("Fut", "Ind") => Lakara::Lrt,
         ("Impf", "Ind") => Lakara::Lan,
         ("Perf", "Ind") => Lakara::Lit,
-        ("Perf", "Sub") => Lakara::None,
         ("Pres", "Imp") => Lakara::Lot,
         ("Pres", "Ind") => Lakara::Lat,
-        ("Pres", "Jus") => Lakara::None,
         ("Pres", "Opt") => Lakara::LinVidhi,
         ("Pres", "Sub") => Lakara::Lot,
-        (&_, &_) => Lakara::None,
-    };
-    Ok(val)
+        (t, m) => {
+            warnings.push(UnconvertedFeature::new("Tense", t));
+            warnings.push(UnconvertedFeature::new("Mood", m));
+            Lakara::None
+        }
+    }
 }
 
-fn parse_verb_pada(_f: &TokenFeatures) -> VerbPada {
-    // FIXME: unsupported in DCS?
+/// Reshapes a DCS voice into a Vidyut pada.
+///
+/// DCS doesn't carry pada directly, so this always reports `VerbPada::None`; if a `Voice`
+/// feature is present, it's surfaced as a warning instead of being silently dropped.
+fn parse_verb_pada(f: &TokenFeatures, warnings: &mut Vec<UnconvertedFeature>) -> VerbPada {
+    if let Some(voice) = f.get("Voice") {
+        warnings.push(UnconvertedFeature::new("Voice", voice));
+    }
     VerbPada::None
 }
+
+/// Converts Vidyut semantics back into a DCS-style token, the inverse of `standardize`.
+///
+/// As with `standardize`, the original surface form isn't always available, so `form` is
+/// set to the lemma, same as the comment on `standardize` explains for `text`.
+pub fn unstandardize(word: &ParsedWord) -> Token {
+    let mut features = TokenFeatures::new();
+    let (upos, lemma) = match &word.semantics {
+        Semantics::Subanta(s) => {
+            unparse_linga(s.linga, &mut features);
+            unparse_vibhakti(s.vibhakti, &mut features);
+            unparse_vacana(s.vacana, &mut features);
+            if s.is_purvapada {
+                features.insert("Case".to_string(), "Cpd".to_string());
+            }
+            let upos = match &s.stem {
+                Stem::Krdanta { tense, .. } => {
+                    unparse_tense(*tense, &mut features);
+                    // `standardize` reads a VERB with `VerbForm` as a participle.
+                    features.insert("VerbForm".to_string(), "Part".to_string());
+                    "VERB"
+                }
+                _ => "NOUN",
+            };
+            (upos, word.text.clone())
+        }
+        Semantics::Tinanta(t) => {
+            unparse_purusha(t.purusha, &mut features);
+            unparse_vacana(t.vacana, &mut features);
+            unparse_lakara(t.lakara, &mut features);
+            ("VERB", t.root.clone())
+        }
+        Semantics::Avyaya => ("ADV", word.text.clone()),
+        Semantics::None => ("MANTRA", word.text.clone()),
+    };
+
+    Token {
+        form: lemma.clone(),
+        lemma,
+        upos: upos.to_string(),
+        features,
+        ..Default::default()
+    }
+}
+
+/// Reshapes a Vidyut tense into its DCS counterpart, mirroring `parse_tense`.
+fn unparse_tense(tense: StemTense, features: &mut TokenFeatures) {
+    let val = match tense {
+        StemTense::Present => "Pres",
+        StemTense::Past => "Past",
+        StemTense::Future => "Fut",
+        StemTense::None => return,
+    };
+    features.insert("Tense".to_string(), val.to_string());
+}
+
+/// Reshapes a Vidyut linga into its DCS gender, mirroring `parse_linga`.
+fn unparse_linga(linga: Linga, features: &mut TokenFeatures) {
+    let val = match linga {
+        Linga::Pum => "Masc",
+        Linga::Stri => "Fem",
+        Linga::Napumsaka => "Neut",
+        Linga::None => return,
+    };
+    features.insert("Gender".to_string(), val.to_string());
+}
+
+/// Reshapes a Vidyut vibhakti into its DCS case, mirroring `parse_vibhakti`.
+///
+/// `Vibhakti::None` is ambiguous going back (it covers both a missing `Case` and DCS's
+/// `Cpd` case), so callers that need to mark a compound member should set that separately,
+/// as `unstandardize` does via `is_purvapada`.
+fn unparse_vibhakti(vibhakti: Vibhakti, features: &mut TokenFeatures) {
+    let val = match vibhakti {
+        Vibhakti::V1 => "Nom",
+        Vibhakti::V2 => "Acc",
+        Vibhakti::V3 => "Ins",
+        Vibhakti::V4 => "Dat",
+        Vibhakti::V5 => "Abl",
+        Vibhakti::V6 => "Gen",
+        Vibhakti::V7 => "Loc",
+        Vibhakti::Sambodhana => "Voc",
+        Vibhakti::None => return,
+    };
+    features.insert("Case".to_string(), val.to_string());
+}
+
+/// Reshapes a Vidyut purusha into its DCS person, mirroring `parse_purusha`.
+fn unparse_purusha(purusha: Purusha, features: &mut TokenFeatures) {
+    let val = match purusha {
+        Purusha::Prathama => "3",
+        Purusha::Madhyama => "2",
+        Purusha::Uttama => "1",
+        Purusha::None => return,
+    };
+    features.insert("Person".to_string(), val.to_string());
+}
+
+/// Reshapes a Vidyut vacana into its DCS number, mirroring `parse_vacana`.
+fn unparse_vacana(vacana: Vacana, features: &mut TokenFeatures) {
+    let val = match vacana {
+        Vacana::Eka => "Sing",
+        Vacana::Dvi => "Dual",
+        Vacana::Bahu => "Plur",
+        Vacana::None => return,
+    };
+    features.insert("Number".to_string(), val.to_string());
+}
+
+/// Reshapes a Vidyut lakara into its DCS tense/mood pair, mirroring `parse_lakara`.
+///
+/// `parse_lakara` collapses several DCS pairs onto the same lakara or onto `Lakara::None`
+/// (e.g. both `(Pres, Imp)` and `(Pres, Sub)` become `Lot`), so this reverse mapping picks
+/// one canonical pair per lakara and can't reconstruct `Lakara::None` at all. `Lut`, which
+/// `parse_lakara` never produces, gets no pair either.
+fn unparse_lakara(lakara: Lakara, features: &mut TokenFeatures) {
+    let pair = match lakara {
+        Lakara::Lun => Some(("Aor", "Ind")),
+        Lakara::LunNoAgama => Some(("Aor", "Jus")),
+        Lakara::LinAshih => Some(("Aor", "Prec")),
+        Lakara::Lrn => Some(("Fut", "Cond")),
+        Lakara::Lrt => Some(("Fut", "Ind")),
+        Lakara::Lan => Some(("Impf", "Ind")),
+        Lakara::Lit => Some(("Perf", "Ind")),
+        Lakara::Lot => Some(("Pres", "Imp")),
+        Lakara::Lat => Some(("Pres", "Ind")),
+        Lakara::LinVidhi => Some(("Pres", "Opt")),
+        Lakara::Lut | Lakara::None => None,
+    };
+    if let Some((tense, mood)) = pair {
+        features.insert("Tense".to_string(), tense.to_string());
+        features.insert("Mood".to_string(), mood.to_string());
+    }
+}
+
+/// Serializes `tokens` as one CoNLL-U sentence, one ID/FORM/LEMMA/UPOS/XPOS/FEATS/HEAD/
+/// DEPREL/DEPS/MISC row per token followed by the blank line that separates sentences.
+///
+/// Vidyut semantics don't carry XPOS, dependency heads/relations, or MISC, so those columns
+/// are filled with CoNLL-U's `_` placeholder; omitting them outright would leave fewer than
+/// the ten mandatory columns and break standard UD tooling.
+pub fn write_conllu_sentence(tokens: &[Token]) -> String {
+    let mut out = String::new();
+    for (i, t) in tokens.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\t{}\t{}\t{}\t_\t{}\t_\t_\t_\t_\n",
+            i + 1,
+            t.form,
+            t.lemma,
+            t.upos,
+            format_features(&t.features),
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+/// Formats `features` as CoNLL-U's `Key1=Val1|Key2=Val2` FEATS column, sorted by key for
+/// deterministic output, or `_` if there are none.
+fn format_features(features: &TokenFeatures) -> String {
+    let mut pairs: Vec<(String, String)> = features
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    if pairs.is_empty() {
+        return "_".to_string();
+    }
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Serializes a document (one `Vec<Token>` per sentence) as a full CoNLL-U text.
+pub fn write_conllu(sentences: &[Vec<Token>]) -> String {
+    sentences
+        .iter()
+        .map(|sentence| write_conllu_sentence(sentence))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(form: &str, lemma: &str, upos: &str, features: &[(&str, &str)]) -> Token {
+        let mut token_features = TokenFeatures::new();
+        for (key, value) in features {
+            token_features.insert(key.to_string(), value.to_string());
+        }
+        Token {
+            id: "1".to_string(),
+            form: form.to_string(),
+            lemma: lemma.to_string(),
+            upos: upos.to_string(),
+            features: token_features,
+        }
+    }
+
+    #[test]
+    fn write_conllu_sentence_emits_all_ten_columns() {
+        let t = token("rAmas", "rAma", "NOUN", &[("Case", "Nom")]);
+        let line = write_conllu_sentence(&[t]);
+        let mut cols = line.lines().next().unwrap().split('\t');
+        assert_eq!(cols.clone().count(), 10);
+        assert_eq!(cols.next(), Some("1"));
+        assert_eq!(cols.next(), Some("rAmas"));
+        assert_eq!(cols.next(), Some("rAma"));
+        assert_eq!(cols.next(), Some("NOUN"));
+        assert_eq!(cols.next(), Some("_")); // XPOS
+        assert_eq!(cols.next(), Some("Case=Nom")); // FEATS
+        assert_eq!(cols.next(), Some("_")); // HEAD
+        assert_eq!(cols.next(), Some("_")); // DEPREL
+        assert_eq!(cols.next(), Some("_")); // DEPS
+        assert_eq!(cols.next(), Some("_")); // MISC
+    }
+
+    #[test]
+    fn write_conllu_separates_sentences_with_a_blank_line() {
+        let sentences = vec![
+            vec![token("rAmas", "rAma", "NOUN", &[])],
+            vec![token("gacCati", "gam", "VERB", &[])],
+        ];
+        let text = write_conllu(&sentences);
+        assert_eq!(text.lines().count(), 4);
+        assert_eq!(text.lines().nth(1), Some(""));
+    }
+
+    #[test]
+    fn standardize_reports_an_unrecognized_upos() {
+        let t = token("x", "x", "INTJ", &[]);
+        assert!(standardize(&t).is_err());
+    }
+
+    #[test]
+    fn standardize_warns_instead_of_failing_on_an_unsupported_feature_value() {
+        let t = token("strI", "strI", "NOUN", &[("Gender", "Common")]);
+        let result = standardize(&t).unwrap();
+        assert_eq!(
+            result.warnings,
+            vec![UnconvertedFeature::new("Gender", "Common")]
+        );
+    }
+
+    #[test]
+    fn parse_lakara_warns_on_an_unsupported_tense_mood_pair() {
+        let mut warnings = Vec::new();
+        let mut f = TokenFeatures::new();
+        f.insert("Tense".to_string(), "Perf".to_string());
+        f.insert("Mood".to_string(), "Sub".to_string());
+        assert_eq!(parse_lakara(&f, &mut warnings), Lakara::None);
+        assert_eq!(
+            warnings,
+            vec![
+                UnconvertedFeature::new("Tense", "Perf"),
+                UnconvertedFeature::new("Mood", "Sub"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unstandardize_round_trips_a_subanta() {
+        let word = ParsedWord {
+            text: "rAma".to_string(),
+            semantics: Semantics::Subanta(Subanta {
+                stem: Stem::Basic {
+                    stem: "rAma".to_string(),
+                    lingas: Vec::new(),
+                },
+                linga: Linga::Pum,
+                vacana: Vacana::Eka,
+                vibhakti: Vibhakti::V1,
+                is_purvapada: false,
+            }),
+        };
+        let t = unstandardize(&word);
+        assert_eq!(t.upos, "NOUN");
+        assert_eq!(t.lemma, "rAma");
+        assert_eq!(t.features.get("Case").unwrap(), "Nom");
+        assert_eq!(t.features.get("Gender").unwrap(), "Masc");
+        assert_eq!(t.features.get("Number").unwrap(), "Sing");
+    }
+}
@@ -0,0 +1,116 @@
+//! A prefix trie over SLP1 words, used to prune sandhi segmentation candidates.
+use crate::conllu::Token;
+use crate::dcs;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+
+/// A word list compiled into a prefix trie, keyed in SLP1.
+///
+/// Segmentation uses this to reject candidate words that no dictionary recognizes and to
+/// abandon a branch as soon as its prefix can no longer lead to any entry. The wordlist is
+/// pluggable: build one from any source, such as a plain file of SLP1 words or the DCS
+/// lemmas already reachable through `dcs::standardize_lemma`.
+#[derive(Debug, Default)]
+pub struct Lexicon {
+    root: Node,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<char, Node>,
+    is_word: bool,
+}
+
+impl Lexicon {
+    /// Creates an empty lexicon.
+    pub fn new() -> Self {
+        Lexicon::default()
+    }
+
+    /// Loads a lexicon from a file with one SLP1 word per line.
+    pub fn from_path(path: &str) -> Result<Self, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        let mut lexicon = Lexicon::new();
+        for word in contents.lines() {
+            let word = word.trim();
+            if !word.is_empty() {
+                lexicon.insert(word);
+            }
+        }
+        Ok(lexicon)
+    }
+
+    /// Adds every lemma reachable through `dcs::standardize` from a DCS corpus, alongside
+    /// whatever a wordlist may already have loaded via `from_path`. Tokens `dcs::standardize`
+    /// can't convert (an unrecognized `upos`) are skipped, same as `FrequencyModel::from_corpus`.
+    pub fn insert_dcs_corpus(&mut self, sentences: &[Vec<Token>]) {
+        for sentence in sentences {
+            for token in sentence {
+                if let Ok(result) = dcs::standardize(token) {
+                    self.insert(&result.word.text);
+                }
+            }
+        }
+    }
+
+    /// Adds `word` to the lexicon.
+    pub fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_word = true;
+    }
+
+    /// Returns whether `word` is a complete entry in the lexicon.
+    pub fn is_word(&self, word: &str) -> bool {
+        self.find(word).map_or(false, |node| node.is_word)
+    }
+
+    /// Returns whether any entry in the lexicon starts with `prefix`.
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    fn find(&self, s: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for c in s.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_lookup() {
+        let mut lexicon = Lexicon::new();
+        lexicon.insert("rAma");
+        lexicon.insert("rAjan");
+
+        assert!(lexicon.is_word("rAma"));
+        assert!(!lexicon.is_word("rAj"));
+        assert!(lexicon.has_prefix("rA"));
+        assert!(!lexicon.has_prefix("ga"));
+    }
+
+    #[test]
+    fn insert_dcs_corpus_adds_standardized_lemmas_and_skips_unconvertible_tokens() {
+        let mut good = Token::default();
+        good.lemma = "rAma".to_string();
+        good.upos = "NOUN".to_string();
+
+        let mut unconvertible = Token::default();
+        unconvertible.lemma = "x".to_string();
+        unconvertible.upos = "INTJ".to_string();
+
+        let mut lexicon = Lexicon::new();
+        lexicon.insert_dcs_corpus(&[vec![good, unconvertible]]);
+
+        assert!(lexicon.is_word("rAma"));
+    }
+}
@@ -1,11 +1,29 @@
+mod conllu;
+mod dcs;
+mod lexicon;
+mod parsing;
+mod scoring;
+mod semantics;
+mod translit;
+
+use crate::lexicon::Lexicon;
+use crate::translit::Scheme;
 use multimap::MultiMap;
 use std::cmp;
+use std::collections::HashMap;
 use std::error::Error;
 use std::process;
 
-type SandhiMap = MultiMap<String, (String, String)>;
+pub(crate) type SandhiMap = MultiMap<String, (String, String)>;
+
+/// How many words deep the segmentation recursion goes before giving up on a branch.
+///
+/// Some rules rewrite the head of the remainder (`second`) to something no shorter
+/// than before, so without a hard ceiling a pathological rule set could recurse
+/// forever even though each individual branch looks productive.
+pub(crate) const MAX_SEGMENT_DEPTH: usize = 64;
 
-fn read_sandhi_rules(tsv_path: &str) -> Result<SandhiMap, Box<dyn Error>> {
+pub(crate) fn read_sandhi_rules(tsv_path: &str) -> Result<SandhiMap, Box<dyn Error>> {
     let mut rules = MultiMap::new();
 
     let mut rdr = csv::ReaderBuilder::new()
@@ -27,41 +45,230 @@ fn read_sandhi_rules(tsv_path: &str) -> Result<SandhiMap, Box<dyn Error>> {
     Ok(rules)
 }
 
-fn split(input: &str, rules: SandhiMap) -> Vec<(String, String)> {
-    let mut res = Vec::new();
-    let len_longest_key = rules.keys().map(|x| x.len()).max().expect("Map is empty");
-    let len_input = input.len();
-    for i in 0..len_input {
-        // Default: split as-is, no sandhi.
-        res.push((
-            String::from(&input[0..i]),
-            String::from(&input[i..len_input]),
-        ));
-
-        for j in i..cmp::min(len_input, i + len_longest_key) {
-            let combination = &input[i..j];
-            match rules.get_vec(combination) {
-                Some(pairs) => {
-                    for (f, s) in pairs {
-                        let first = String::from(&input[0..i]) + f;
-                        let second = String::from(s) + &input[j..len_input];
-                        res.push((first, second))
-                    }
+pub(crate) type JoinMap = MultiMap<(String, String), String>;
+
+/// Reads `data/sandhi.tsv` keyed for forward sandhi: `(first, second) -> result`, the
+/// mirror image of `read_sandhi_rules`'s `result -> (first, second)`.
+pub(crate) fn read_join_rules(tsv_path: &str) -> Result<JoinMap, Box<dyn Error>> {
+    let mut rules = JoinMap::new();
+
+    let mut rdr = csv::ReaderBuilder::new()
+        .delimiter(b'\t')
+        .from_path(tsv_path)?;
+    for maybe_row in rdr.records() {
+        let row = maybe_row?;
+        let first = String::from(&row[0]);
+        let second = String::from(&row[1]);
+        let result = String::from(&row[2]);
+        rules.insert((first.clone(), second.clone()), result.clone());
+
+        let result_no_spaces = result.replace(" ", "");
+        if result_no_spaces != result {
+            rules.insert((first, second), result_no_spaces);
+        }
+    }
+    Ok(rules)
+}
+
+/// Joins `left` and `right` via sandhi, returning every surface form the rule table allows.
+///
+/// This mirrors the reverse-sandhi lookup in `solutions`: for each suffix of `left` and prefix of `right`, if the
+/// pair matches a rule's `(first, second)` columns, the rule's result is substituted in.
+/// The unmodified concatenation is always included too, since sandhi is often optional.
+fn apply_join_rules(left: &str, right: &str, rules: &JoinMap) -> Vec<String> {
+    let mut out = vec![String::from(left) + right];
+    let len_left = left.len();
+    let len_right = right.len();
+    for i in 0..=len_left {
+        let suffix = &left[i..];
+        for j in 0..=len_right {
+            let prefix = &right[..j];
+            let key = (suffix.to_string(), prefix.to_string());
+            if let Some(results) = rules.get_vec(&key) {
+                for result in results {
+                    out.push(String::from(&left[..i]) + result + &right[j..]);
                 }
+            }
+        }
+    }
+    out
+}
+
+/// Joins `left` and `right` via sandhi, returning every surface form `data/sandhi.tsv`
+/// allows.
+pub fn join(left: &str, right: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let rules = read_join_rules("data/sandhi.tsv")?;
+    Ok(apply_join_rules(left, right, &rules))
+}
+
+/// Joins a sequence of words left-to-right via sandhi, returning every surface form the
+/// rule table allows for the whole sequence.
+pub fn join_words(words: &[String]) -> Result<Vec<String>, Box<dyn Error>> {
+    let rules = read_join_rules("data/sandhi.tsv")?;
+    let mut candidates = match words.first() {
+        Some(first) => vec![first.clone()],
+        None => return Ok(vec![String::new()]),
+    };
+    for word in &words[1..] {
+        candidates = candidates
+            .iter()
+            .flat_map(|left| apply_join_rules(left, word, &rules))
+            .collect();
+    }
+    Ok(candidates)
+}
+
+/// Prepends `word` to an already-resolved word sequence.
+fn prepend(word: &str, mut words: Vec<String>) -> Vec<String> {
+    words.insert(0, word.to_string());
+    words
+}
+
+/// Returns every way to resolve `rest` into a sequence of dictionary words, memoized on
+/// `rest` itself.
+///
+/// `rest` is used as the memo key instead of an index into the original input because a
+/// sandhi rule can rewrite the head of the remainder (`second`), so the same logical
+/// position can be reached with different remaining text.
+fn solutions(
+    rest: &str,
+    rules: &SandhiMap,
+    lexicon: &Lexicon,
+    len_longest_key: usize,
+    depth: usize,
+    memo: &mut HashMap<String, Vec<Vec<String>>>,
+) -> Vec<Vec<String>> {
+    if rest.is_empty() {
+        return vec![Vec::new()];
+    }
+    if depth >= MAX_SEGMENT_DEPTH {
+        return Vec::new();
+    }
+    if let Some(cached) = memo.get(rest) {
+        return cached.clone();
+    }
+
+    let mut out = Vec::new();
+    let len_rest = rest.len();
+    for i in 1..=len_rest {
+        let left = &rest[0..i];
+        if !lexicon.has_prefix(left) {
+            // No dictionary entry starts with `left`, so no longer prefix will either.
+            break;
+        }
+
+        // Default: split as-is here, no sandhi.
+        if lexicon.is_word(left) {
+            for tail in solutions(&rest[i..], rules, lexicon, len_longest_key, depth + 1, memo) {
+                out.push(prepend(left, tail));
+            }
+        }
+
+        for j in i..=cmp::min(len_rest, i + len_longest_key) {
+            let window = &rest[i..j];
+            let pairs = match rules.get_vec(window) {
+                Some(pairs) => pairs,
                 None => continue,
+            };
+            for (first, second) in pairs {
+                if first.is_empty() && second.is_empty() {
+                    // A rule whose `first` and `second` are both empty restores no text to
+                    // either word; such rules are skipped.
+                    continue;
+                }
+                let left_word = String::from(left) + first;
+                if !lexicon.is_word(&left_word) {
+                    continue;
+                }
+                let remainder = String::from(second) + &rest[j..];
+                for tail in
+                    solutions(&remainder, rules, lexicon, len_longest_key, depth + 1, memo)
+                {
+                    out.push(prepend(&left_word, tail));
+                }
             }
         }
     }
-    res
+
+    memo.insert(rest.to_string(), out.clone());
+    out
+}
+
+/// Recursively resolves `input` into every full word sequence that covers it end to end.
+///
+/// This generalizes a single two-way sandhi split into a lattice of splits, in the spirit
+/// of the finite-state segmentation used by the Sanskrit Heritage engine: at each seam we
+/// either cut as-is or apply a reverse-sandhi rule, then recurse on what's left. `lexicon`
+/// prunes the search to word sequences that are actually attested, which keeps whole-sentence
+/// segmentation tractable.
+fn segment(input: &str, rules: &SandhiMap, lexicon: &Lexicon) -> Vec<Vec<String>> {
+    let len_longest_key = rules.keys().map(|x| x.len()).max().unwrap_or(0);
+    let mut memo = HashMap::new();
+    solutions(input, rules, lexicon, len_longest_key, 0, &mut memo)
+}
+
+/// Segments `input`, which may be written in Devanagari, IAST, Harvard-Kyoto, or SLP1.
+///
+/// `input_scheme` selects which of those `input` is written in; pass `None` to auto-detect
+/// from `input`'s character repertoire. Auto-detection can't tell Harvard-Kyoto apart from
+/// SLP1 (see `translit::detect_scheme`), so Harvard-Kyoto input must be named explicitly.
+/// `input` is normalized to SLP1 (the scheme the sandhi rules and lexicon are keyed in)
+/// before segmenting. Each output word is then transliterated into `output_scheme`, or back
+/// into `input`'s own scheme if `output_scheme` is `None`.
+pub fn segment_text(
+    input: &str,
+    input_scheme: Option<Scheme>,
+    output_scheme: Option<Scheme>,
+) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+    let input_scheme = input_scheme.unwrap_or_else(|| translit::detect_scheme(input));
+    let output_scheme = output_scheme.unwrap_or(input_scheme);
+    let slp1_input = translit::transliterate(input, input_scheme, Scheme::Slp1);
+
+    let rules = read_sandhi_rules("data/sandhi.tsv")?;
+    let lexicon = Lexicon::from_path("data/words.txt")?;
+
+    Ok(segment(&slp1_input, &rules, &lexicon)
+        .into_iter()
+        .map(|words| {
+            words
+                .into_iter()
+                .map(|word| translit::transliterate(&word, Scheme::Slp1, output_scheme))
+                .collect()
+        })
+        .collect())
+}
+
+/// Parses a CLI scheme argument (case-insensitive) into a `Scheme`.
+///
+/// `translit::detect_scheme` can't tell Harvard-Kyoto apart from SLP1 on its own, so this is
+/// the only way to get Harvard-Kyoto input segmented correctly from the CLI.
+fn parse_scheme_arg(s: &str) -> Option<Scheme> {
+    match s.to_lowercase().as_str() {
+        "devanagari" => Some(Scheme::Devanagari),
+        "iast" => Some(Scheme::Iast),
+        "hk" | "harvard-kyoto" => Some(Scheme::HarvardKyoto),
+        "slp1" => Some(Scheme::Slp1),
+        _ => None,
+    }
 }
 
 fn main() {
-    let text = std::env::args().nth(1).expect("No text provided.");
+    let mut args = std::env::args().skip(1);
+    let text = args.next().expect("No text provided.");
+    let input_scheme = args.next().map(|arg| {
+        parse_scheme_arg(&arg).unwrap_or_else(|| {
+            eprintln!(
+                "Unknown scheme `{}`; expected one of devanagari, iast, hk, slp1.",
+                arg
+            );
+            process::exit(1);
+        })
+    });
 
-    match read_sandhi_rules("data/sandhi.tsv") {
-        Ok(data) => {
-            for (first, second) in split(&text, data) {
-                println!("{} {}", first, second);
+    match segment_text(&text, input_scheme, None) {
+        Ok(segmentations) => {
+            for words in segmentations {
+                println!("{}", words.join(" "));
             }
         }
         Err(err) => {
@@ -72,10 +279,68 @@ fn main() {
 }
 
 #[cfg(test)]
-mod test {
+mod tests {
     use super::*;
+
     #[test]
-    fn my_test() {
-        main()
+    fn segment_splits_known_words_with_no_sandhi_needed() {
+        let rules = MultiMap::new();
+        let mut lexicon = Lexicon::new();
+        lexicon.insert("rAma");
+        lexicon.insert("gacCati");
+
+        let result = segment("rAmagacCati", &rules, &lexicon);
+
+        assert_eq!(
+            result,
+            vec![vec!["rAma".to_string(), "gacCati".to_string()]]
+        );
+    }
+
+    #[test]
+    fn segment_applies_a_sandhi_rule_to_recover_the_source_words() {
+        let mut rules = MultiMap::new();
+        // a + u -> o, e.g. "rAma" + "uvAca" -> "rAmovAca".
+        rules.insert("o".to_string(), ("a".to_string(), "u".to_string()));
+        let mut lexicon = Lexicon::new();
+        lexicon.insert("rAma");
+        lexicon.insert("uvAca");
+
+        let result = segment("rAmovAca", &rules, &lexicon);
+
+        assert!(result.contains(&vec!["rAma".to_string(), "uvAca".to_string()]));
+    }
+
+    #[test]
+    fn segment_finds_nothing_for_an_unknown_word() {
+        let rules = MultiMap::new();
+        let mut lexicon = Lexicon::new();
+        lexicon.insert("rAma");
+
+        assert_eq!(segment("devadattaH", &rules, &lexicon), Vec::<Vec<String>>::new());
+    }
+
+    #[test]
+    fn apply_join_rules_always_includes_the_plain_concatenation() {
+        let rules = JoinMap::new();
+        assert_eq!(
+            apply_join_rules("rAma", "uvAca", &rules),
+            vec!["rAmauvAca".to_string()]
+        );
+    }
+
+    #[test]
+    fn apply_join_rules_applies_a_matching_rule() {
+        let mut rules = JoinMap::new();
+        rules.insert(
+            ("a".to_string(), "u".to_string()),
+            "o".to_string(),
+        );
+
+        let result = apply_join_rules("rAma", "uvAca", &rules);
+
+        assert!(result.contains(&"rAmovAca".to_string()));
+        // The unmodified concatenation is still offered alongside the sandhi form.
+        assert!(result.contains(&"rAmauvAca".to_string()));
     }
 }
@@ -0,0 +1,11 @@
+//! The structural representation `dcs::standardize` converts DCS tokens into.
+use crate::semantics::Semantics;
+
+/// A single standardized word: its lemma/root (already run through
+/// `dcs::standardize_lemma`) and the semantic structure parsed from its DCS morphological
+/// features.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedWord {
+    pub text: String,
+    pub semantics: Semantics,
+}
@@ -0,0 +1,258 @@
+//! Corpus-frequency scoring for sandhi segmentations.
+//!
+//! This builds a unigram/bigram frequency model over lemmas from the DCS corpus (reusing
+//! `dcs::standardize`) and uses it to rank the segmentations produced by the lattice in
+//! `solutions`/`segment`, effectively turning that lattice into a Viterbi best-path search:
+//! instead of keeping every path through a remainder, we keep only the `k` cheapest, so
+//! dominated partial paths are pruned as soon as a remainder is revisited.
+use crate::conllu::read_conllu_file;
+use crate::dcs;
+use crate::lexicon::Lexicon;
+use crate::{read_sandhi_rules, SandhiMap, MAX_SEGMENT_DEPTH};
+use std::cmp;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::io::Write;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Add-one smoothing constant added to the vocabulary size when estimating unigram costs.
+const UNIGRAM_SMOOTHING: f64 = 1.0;
+
+/// A unigram/bigram frequency model over lemmas, used to cost sandhi segmentations.
+#[derive(Debug, Default)]
+pub struct FrequencyModel {
+    unigrams: HashMap<String, u64>,
+    bigrams: HashMap<(String, String), u64>,
+    total_unigrams: u64,
+}
+
+impl FrequencyModel {
+    pub fn new() -> Self {
+        FrequencyModel::default()
+    }
+
+    /// Builds a frequency model by standardizing every token of a DCS corpus in CoNLL-U
+    /// format. Tokens that `dcs::standardize` can't convert are skipped.
+    pub fn from_corpus(conllu_path: &str) -> Result<Self> {
+        let mut model = FrequencyModel::new();
+        for sentence in read_conllu_file(conllu_path)? {
+            let mut prev_lemma: Option<String> = None;
+            for token in &sentence {
+                let lemma = match dcs::standardize(token) {
+                    Ok(result) => result.word.text,
+                    Err(_) => continue,
+                };
+                *model.unigrams.entry(lemma.clone()).or_insert(0) += 1;
+                model.total_unigrams += 1;
+                if let Some(prev) = prev_lemma {
+                    *model.bigrams.entry((prev, lemma.clone())).or_insert(0) += 1;
+                }
+                prev_lemma = Some(lemma);
+            }
+        }
+        Ok(model)
+    }
+
+    /// Persists the model as `<path>.1g` (unigram counts) and `<path>.2g` (bigram counts),
+    /// each a simple tab-separated file.
+    pub fn save(&self, path: &str) -> Result<()> {
+        let mut unigram_file = fs::File::create(format!("{}.1g", path))?;
+        for (word, count) in &self.unigrams {
+            writeln!(unigram_file, "{}\t{}", word, count)?;
+        }
+
+        let mut bigram_file = fs::File::create(format!("{}.2g", path))?;
+        for ((prev, word), count) in &self.bigrams {
+            writeln!(bigram_file, "{}\t{}\t{}", prev, word, count)?;
+        }
+        Ok(())
+    }
+
+    /// Loads a model previously written by `save`.
+    pub fn load(path: &str) -> Result<Self> {
+        let mut model = FrequencyModel::new();
+        for line in fs::read_to_string(format!("{}.1g", path))?.lines() {
+            let mut cols = line.split('\t');
+            let word = cols.next().ok_or("missing word column")?.to_string();
+            let count: u64 = cols.next().ok_or("missing count column")?.parse()?;
+            model.unigrams.insert(word, count);
+            model.total_unigrams += count;
+        }
+        for line in fs::read_to_string(format!("{}.2g", path))?.lines() {
+            let mut cols = line.split('\t');
+            let prev = cols.next().ok_or("missing prev column")?.to_string();
+            let word = cols.next().ok_or("missing word column")?.to_string();
+            let count: u64 = cols.next().ok_or("missing count column")?.parse()?;
+            model.bigrams.insert((prev, word), count);
+        }
+        Ok(model)
+    }
+
+    /// Returns `-log p(word)`, with add-one smoothing for words outside the corpus.
+    fn unigram_cost(&self, word: &str) -> f64 {
+        let count = *self.unigrams.get(word).unwrap_or(&0) as f64;
+        let vocab_size = self.unigrams.len() as f64 + UNIGRAM_SMOOTHING;
+        let total = self.total_unigrams as f64 + vocab_size;
+        -((count + 1.0) / total).ln()
+    }
+
+    /// Returns `-log p(word | prev)`, add-one smoothed over how often `prev` was seen at
+    /// all. This is an additional transition term layered on top of `unigram_cost`, not a
+    /// replacement for it, so it must not fall back to `unigram_cost` itself: doing so would
+    /// double-count `word`'s own frequency wherever the pair is unattested.
+    fn bigram_cost(&self, prev: &str, word: &str) -> f64 {
+        let pair_count = self
+            .bigrams
+            .get(&(prev.to_string(), word.to_string()))
+            .copied()
+            .unwrap_or(0) as f64;
+        let prev_count = self.unigrams.get(prev).copied().unwrap_or(0) as f64;
+        let vocab_size = self.unigrams.len() as f64 + UNIGRAM_SMOOTHING;
+        -((pair_count + 1.0) / (prev_count + vocab_size)).ln()
+    }
+}
+
+/// A candidate continuation of the segmentation lattice: the words chosen so far, in
+/// order, and their total cost under the frequency model.
+#[derive(Debug, Clone)]
+struct Path {
+    words: Vec<String>,
+    cost: f64,
+}
+
+fn prepend_path(word: &str, model: &FrequencyModel, mut tail: Path) -> Path {
+    // Every word contributes its own unigram cost, plus a bigram transition cost for
+    // each adjacent pair. Anchoring the unigram term on the word being prepended (rather
+    // than on whichever word the right-to-left recursion happens to bottom out on) is
+    // what keeps the first word of the sequence from going unscored.
+    let own_cost = model.unigram_cost(word);
+    let transition_cost = match tail.words.first() {
+        Some(next) => model.bigram_cost(word, next),
+        None => 0.0,
+    };
+    tail.words.insert(0, word.to_string());
+    Path {
+        words: tail.words,
+        cost: own_cost + transition_cost + tail.cost,
+    }
+}
+
+/// Viterbi walk over the segmentation lattice: like `solutions`, but keeps only the `k`
+/// cheapest paths through each remainder instead of every path.
+fn best_paths(
+    rest: &str,
+    rules: &SandhiMap,
+    lexicon: &Lexicon,
+    model: &FrequencyModel,
+    len_longest_key: usize,
+    depth: usize,
+    k: usize,
+    memo: &mut HashMap<String, Vec<Path>>,
+) -> Vec<Path> {
+    if rest.is_empty() {
+        return vec![Path {
+            words: Vec::new(),
+            cost: 0.0,
+        }];
+    }
+    if depth >= MAX_SEGMENT_DEPTH {
+        return Vec::new();
+    }
+    if let Some(cached) = memo.get(rest) {
+        return cached.clone();
+    }
+
+    let mut candidates = Vec::new();
+    let len_rest = rest.len();
+    for i in 1..=len_rest {
+        let left = &rest[0..i];
+        if !lexicon.has_prefix(left) {
+            break;
+        }
+
+        if lexicon.is_word(left) {
+            for tail in best_paths(
+                &rest[i..],
+                rules,
+                lexicon,
+                model,
+                len_longest_key,
+                depth + 1,
+                k,
+                memo,
+            ) {
+                candidates.push(prepend_path(left, model, tail));
+            }
+        }
+
+        for j in i..=cmp::min(len_rest, i + len_longest_key) {
+            let window = &rest[i..j];
+            let pairs = match rules.get_vec(window) {
+                Some(pairs) => pairs,
+                None => continue,
+            };
+            for (first, second) in pairs {
+                if first.is_empty() && second.is_empty() {
+                    continue;
+                }
+                let left_word = String::from(left) + first;
+                if !lexicon.is_word(&left_word) {
+                    continue;
+                }
+                let remainder = String::from(second) + &rest[j..];
+                for tail in best_paths(
+                    &remainder,
+                    rules,
+                    lexicon,
+                    model,
+                    len_longest_key,
+                    depth + 1,
+                    k,
+                    memo,
+                ) {
+                    candidates.push(prepend_path(&left_word, model, tail));
+                }
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| a.cost.partial_cmp(&b.cost).unwrap());
+    candidates.truncate(k);
+    memo.insert(rest.to_string(), candidates.clone());
+    candidates
+}
+
+/// Ranks the `k` cheapest full segmentations of `input`, cheapest first.
+fn rank_segmentations(
+    input: &str,
+    rules: &SandhiMap,
+    lexicon: &Lexicon,
+    model: &FrequencyModel,
+    k: usize,
+) -> Vec<(Vec<String>, f64)> {
+    let len_longest_key = rules.keys().map(|x| x.len()).max().unwrap_or(0);
+    let mut memo = HashMap::new();
+    let mut paths = best_paths(input, rules, lexicon, model, len_longest_key, 0, k, &mut memo);
+    paths.sort_by(|a, b| a.cost.partial_cmp(&b.cost).unwrap());
+    paths.truncate(k);
+    paths.into_iter().map(|p| (p.words, p.cost)).collect()
+}
+
+/// Returns the `k` most probable segmentations of `input`, most probable first, using the
+/// default data files.
+pub fn n_best(input: &str, k: usize) -> Result<Vec<Vec<String>>> {
+    let rules = read_sandhi_rules("data/sandhi.tsv")?;
+    let lexicon = Lexicon::from_path("data/words.txt")?;
+    let model = FrequencyModel::load("data/dcs_frequencies")?;
+    Ok(rank_segmentations(input, &rules, &lexicon, &model, k)
+        .into_iter()
+        .map(|(words, _)| words)
+        .collect())
+}
+
+/// Returns the single most probable segmentation of `input`, using the default data files.
+pub fn best_segmentation(input: &str) -> Result<Vec<String>> {
+    Ok(n_best(input, 1)?.into_iter().next().unwrap_or_default())
+}
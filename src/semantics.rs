@@ -0,0 +1,125 @@
+//! Vidyut's semantic representation of a standardized word, independent of any single
+//! corpus's own tagset (see `dcs::standardize`/`dcs::unstandardize` for the DCS-specific
+//! mapping onto these types).
+
+/// What kind of word this is, and the morphology particular to that kind.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Semantics {
+    Subanta(Subanta),
+    Tinanta(Tinanta),
+    Avyaya,
+    None,
+}
+
+/// A nominal (noun, pronoun, adjective, or participle) inflected for linga/vacana/vibhakti.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subanta {
+    pub stem: Stem,
+    pub linga: Linga,
+    pub vacana: Vacana,
+    pub vibhakti: Vibhakti,
+    pub is_purvapada: bool,
+}
+
+/// A finite verb inflected for purusha/vacana/lakara/pada.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tinanta {
+    pub root: String,
+    pub purusha: Purusha,
+    pub vacana: Vacana,
+    pub lakara: Lakara,
+    pub pada: VerbPada,
+}
+
+/// A subanta's stem: a bare nominal stem, or a participle derived from a verb root.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stem {
+    Basic { stem: String, lingas: Vec<Linga> },
+    Krdanta {
+        root: String,
+        tense: StemTense,
+        prayoga: StemPrayoga,
+    },
+}
+
+/// Grammatical gender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Linga {
+    None,
+    Pum,
+    Stri,
+    Napumsaka,
+}
+
+/// Grammatical number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vacana {
+    None,
+    Eka,
+    Dvi,
+    Bahu,
+}
+
+/// Nominal case (the seven vibhaktis, plus `Sambodhana` for the vocative).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vibhakti {
+    None,
+    V1,
+    V2,
+    V3,
+    V4,
+    V5,
+    V6,
+    V7,
+    Sambodhana,
+}
+
+/// Grammatical person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Purusha {
+    None,
+    Prathama,
+    Madhyama,
+    Uttama,
+}
+
+/// A krdanta's tense.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StemTense {
+    None,
+    Present,
+    Past,
+    Future,
+}
+
+/// A krdanta's voice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StemPrayoga {
+    None,
+    Kartari,
+    Karmani,
+    Bhave,
+}
+
+/// A verb's pada (voice-like inflectional category, distinct from `StemPrayoga`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerbPada {
+    None,
+}
+
+/// A finite verb's lakara (tense-mood).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lakara {
+    None,
+    Lat,
+    Lit,
+    Lut,
+    Lrt,
+    Lot,
+    Lan,
+    LinVidhi,
+    LinAshih,
+    Lun,
+    LunNoAgama,
+    Lrn,
+}
@@ -0,0 +1,302 @@
+//! Transliteration between SLP1 (the scheme the rest of the crate operates on internally)
+//! and the input/output schemes a caller is likely to actually have on hand.
+use std::collections::HashMap;
+
+/// A transliteration scheme a caller's text might be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    Devanagari,
+    Iast,
+    HarvardKyoto,
+    Slp1,
+}
+
+/// (SLP1, IAST, Harvard-Kyoto, Devanagari) for each vowel, with its Devanagari dependent
+/// vowel sign (empty for "a", whose vowel is implicit on a consonant).
+const VOWELS: &[(&str, &str, &str, &str, &str)] = &[
+    ("a", "a", "a", "अ", ""),
+    ("A", "ā", "A", "आ", "ा"),
+    ("i", "i", "i", "इ", "ि"),
+    ("I", "ī", "I", "ई", "ी"),
+    ("u", "u", "u", "उ", "ु"),
+    ("U", "ū", "U", "ऊ", "ू"),
+    ("f", "ṛ", "R", "ऋ", "ृ"),
+    ("F", "ṝ", "RR", "ॠ", "ॄ"),
+    ("x", "ḷ", "lR", "ऌ", "ॢ"),
+    ("X", "ḹ", "lRR", "ॡ", "ॣ"),
+    ("e", "e", "e", "ए", "े"),
+    ("E", "ai", "ai", "ऐ", "ै"),
+    ("o", "o", "o", "ओ", "ो"),
+    ("O", "au", "au", "औ", "ौ"),
+];
+
+/// (SLP1, IAST, Harvard-Kyoto, Devanagari) for each consonant (Devanagari glyph carries an
+/// implicit "a").
+const CONSONANTS: &[(&str, &str, &str, &str)] = &[
+    ("k", "k", "k", "क"),
+    ("K", "kh", "kh", "ख"),
+    ("g", "g", "g", "ग"),
+    ("G", "gh", "gh", "घ"),
+    ("N", "ṅ", "G", "ङ"),
+    ("c", "c", "c", "च"),
+    ("C", "ch", "ch", "छ"),
+    ("j", "j", "j", "ज"),
+    ("J", "jh", "jh", "झ"),
+    ("Y", "ñ", "J", "ञ"),
+    ("w", "ṭ", "T", "ट"),
+    ("W", "ṭh", "Th", "ठ"),
+    ("q", "ḍ", "D", "ड"),
+    ("Q", "ḍh", "Dh", "ढ"),
+    ("R", "ṇ", "N", "ण"),
+    ("t", "t", "t", "त"),
+    ("T", "th", "th", "थ"),
+    ("d", "d", "d", "द"),
+    ("D", "dh", "dh", "ध"),
+    ("n", "n", "n", "न"),
+    ("p", "p", "p", "प"),
+    ("P", "ph", "ph", "फ"),
+    ("b", "b", "b", "ब"),
+    ("B", "bh", "bh", "भ"),
+    ("m", "m", "m", "म"),
+    ("y", "y", "y", "य"),
+    ("r", "r", "r", "र"),
+    ("l", "l", "l", "ल"),
+    ("v", "v", "v", "व"),
+    ("S", "ś", "z", "श"),
+    ("z", "ṣ", "S", "ष"),
+    ("s", "s", "s", "स"),
+    ("h", "h", "h", "ह"),
+];
+
+/// (SLP1, IAST, Harvard-Kyoto, Devanagari) for marks that attach to the previous syllable.
+const MARKS: &[(&str, &str, &str, &str)] = &[
+    ("M", "ṃ", "M", "ं"),
+    ("H", "ḥ", "H", "ः"),
+    ("'", "'", "'", "ऽ"),
+];
+
+/// Devanagari's vowel-suppressing virama/halant.
+const VIRAMA: char = '\u{094D}';
+
+fn scheme_index(scheme: Scheme) -> usize {
+    match scheme {
+        Scheme::Iast => 1,
+        Scheme::HarvardKyoto => 2,
+        Scheme::Devanagari => 3,
+        Scheme::Slp1 => 0,
+    }
+}
+
+/// Guesses the scheme of `s` from its character repertoire.
+///
+/// Devanagari and IAST are detected from their distinctive code points. All-ASCII text is
+/// assumed to be SLP1, the scheme the rest of the crate operates on; Harvard-Kyoto is also
+/// all-ASCII and gives many of the same letters different values (HK `T` is SLP1 `w`, HK
+/// `kh` is SLP1 `K`), and this function does not try to tell the two apart. Callers who
+/// actually have Harvard-Kyoto input must pass
+/// `Scheme::HarvardKyoto` explicitly (see `transliterate`) rather than rely on detection.
+pub fn detect_scheme(s: &str) -> Scheme {
+    if s.chars().any(|c| ('\u{0900}'..='\u{097F}').contains(&c)) {
+        return Scheme::Devanagari;
+    }
+    if s.chars().any(|c| !c.is_ascii()) {
+        return Scheme::Iast;
+    }
+    Scheme::Slp1
+}
+
+/// Transliterates `s` from SLP1 into `scheme`.
+fn from_slp1(s: &str, scheme: Scheme) -> String {
+    let i = scheme_index(scheme);
+    if i == 0 {
+        return s.to_string();
+    }
+
+    let mut out = String::new();
+    let chars: Vec<char> = s.chars().collect();
+    let mut pos = 0;
+    while pos < chars.len() {
+        // Consonants and marks are up to two SLP1 characters; try the longer match first.
+        let mut matched = false;
+        for len in (1..=2).rev() {
+            if pos + len > chars.len() {
+                continue;
+            }
+            let token: String = chars[pos..pos + len].iter().collect();
+            if let Some(row) = CONSONANTS.iter().find(|row| row.0 == token) {
+                out.push_str([row.1, row.2, row.3][i - 1]);
+                // Devanagari's consonant glyph already carries an implicit "a"; an
+                // explicit "a" that follows is just that implicit vowel, not a second one.
+                if scheme == Scheme::Devanagari && chars.get(pos + len) == Some(&'a') {
+                    pos += len + 1;
+                } else if scheme == Scheme::Devanagari {
+                    if let Some(vrow) = chars
+                        .get(pos + len)
+                        .and_then(|c| VOWELS.iter().find(|row| row.0.starts_with(*c)))
+                    {
+                        out.push_str(vrow.4);
+                        pos += len + 1;
+                    } else {
+                        out.push(VIRAMA);
+                        pos += len;
+                    }
+                } else {
+                    pos += len;
+                }
+                matched = true;
+                break;
+            }
+            if let Some(row) = MARKS.iter().find(|row| row.0 == token) {
+                out.push_str([row.1, row.2, row.3][i - 1]);
+                pos += len;
+                matched = true;
+                break;
+            }
+        }
+        if matched {
+            continue;
+        }
+
+        if let Some(row) = VOWELS.iter().find(|row| row.0 == chars[pos].to_string()) {
+            out.push_str([row.1, row.2, row.3][i - 1]);
+            pos += 1;
+            continue;
+        }
+
+        out.push(chars[pos]);
+        pos += 1;
+    }
+    out
+}
+
+/// Transliterates `s` from `scheme` into SLP1.
+///
+/// This is the inverse of `from_slp1`: text is greedily tokenized against the spellings
+/// for `scheme`, with Devanagari needing the extra step of resolving each consonant's
+/// implicit or written vowel.
+fn slp1_from(s: &str, scheme: Scheme) -> String {
+    let i = scheme_index(scheme);
+    if i == 0 {
+        return s.to_string();
+    }
+
+    let mut out = String::new();
+    let chars: Vec<char> = s.chars().collect();
+    let mut pos = 0;
+    while pos < chars.len() {
+        if scheme == Scheme::Devanagari {
+            let c = chars[pos].to_string();
+            if let Some(row) = CONSONANTS.iter().find(|row| row.3 == c) {
+                out.push_str(row.0);
+                match chars.get(pos + 1) {
+                    Some(&next) if next == VIRAMA => {
+                        pos += 2;
+                    }
+                    Some(&next) if VOWELS.iter().any(|v| v.4 == next.to_string()) => {
+                        let vrow = VOWELS.iter().find(|v| v.4 == next.to_string()).unwrap();
+                        out.push_str(vrow.0);
+                        pos += 2;
+                    }
+                    _ => {
+                        out.push('a');
+                        pos += 1;
+                    }
+                }
+                continue;
+            }
+            if let Some(row) = VOWELS.iter().find(|row| row.3 == c) {
+                out.push_str(row.0);
+                pos += 1;
+                continue;
+            }
+            if let Some(row) = MARKS.iter().find(|row| row.3 == c) {
+                out.push_str(row.0);
+                pos += 1;
+                continue;
+            }
+            // Punctuation (danda, digits, ...) has no SLP1 spelling; drop it rather than
+            // letting it through raw, which would leave non-ASCII bytes in the "SLP1"
+            // string and break every downstream byte-indexed lookup keyed on it.
+            pos += 1;
+            continue;
+        }
+
+        // IAST and Harvard-Kyoto are both plain alphabetic schemes: try progressively
+        // shorter windows (longest match wins) against their consonant/vowel spellings.
+        let mut matched = false;
+        for len in (1..=3).rev() {
+            if pos + len > chars.len() {
+                continue;
+            }
+            let token: String = chars[pos..pos + len].iter().collect();
+            if let Some(row) = CONSONANTS.iter().find(|row| [row.1, row.2][i - 1] == token) {
+                out.push_str(row.0);
+                pos += len;
+                matched = true;
+                break;
+            }
+            if let Some(row) = MARKS.iter().find(|row| [row.1, row.2][i - 1] == token) {
+                out.push_str(row.0);
+                pos += len;
+                matched = true;
+                break;
+            }
+            if let Some(row) = VOWELS.iter().find(|row| [row.1, row.2][i - 1] == token) {
+                out.push_str(row.0);
+                pos += len;
+                matched = true;
+                break;
+            }
+        }
+        if matched {
+            continue;
+        }
+
+        // Same reasoning as the Devanagari branch above: an unmapped character has no
+        // SLP1 spelling, so it's dropped instead of passed through raw.
+        pos += 1;
+    }
+    out
+}
+
+/// Transliterates `s` from `from` into `to`, pivoting through SLP1.
+pub fn transliterate(s: &str, from: Scheme, to: Scheme) -> String {
+    from_slp1(&slp1_from(s, from), to)
+}
+
+/// Transliterates `s` into SLP1, auto-detecting its scheme.
+pub fn to_slp1(s: &str) -> String {
+    slp1_from(s, detect_scheme(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iast_to_slp1() {
+        assert_eq!(to_slp1("rāma"), "rAma");
+        assert_eq!(to_slp1("kṛṣṇa"), "kfzRa");
+    }
+
+    #[test]
+    fn slp1_to_devanagari() {
+        assert_eq!(transliterate("rAma", Scheme::Slp1, Scheme::Devanagari), "राम");
+    }
+
+    #[test]
+    fn aspirates_round_trip_through_slp1() {
+        assert_eq!(to_slp1("bhagavān"), "BagavAn");
+        assert_eq!(
+            transliterate("BagavAn", Scheme::Slp1, Scheme::Devanagari),
+            "भगवान्"
+        );
+    }
+
+    #[test]
+    fn unmapped_characters_are_dropped_instead_of_passed_through() {
+        // The danda has no SLP1 spelling; it must not survive into the "SLP1" output as a
+        // raw multi-byte character, since callers byte-index that string.
+        assert_eq!(to_slp1("रामः।"), "rAmaH");
+    }
+}